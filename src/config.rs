@@ -0,0 +1,62 @@
+use pyo3::prelude::*;
+use songbird::driver::DecodeMode;
+use songbird::Config;
+
+#[pyclass(name = "DecodeMode")]
+#[derive(Clone)]
+pub struct PyDecodeMode {
+    pub decode_mode: DecodeMode,
+}
+
+#[pymethods]
+impl PyDecodeMode {
+    #[staticmethod]
+    fn decrypt() -> Self {
+        //! Voice packets are decrypted but left as Opus. The raw payload is
+        //! handed to receivers.
+        PyDecodeMode {
+            decode_mode: DecodeMode::Decrypt,
+        }
+    }
+
+    #[staticmethod]
+    fn decode() -> Self {
+        //! Voice packets are decrypted and the Opus payload is decoded to i16
+        //! PCM before being handed to receivers.
+        PyDecodeMode {
+            decode_mode: DecodeMode::Decode,
+        }
+    }
+
+    #[staticmethod]
+    fn pass_through() -> Self {
+        //! Voice packets are passed through untouched. Receivers see the raw
+        //! encrypted RTP payload.
+        PyDecodeMode {
+            decode_mode: DecodeMode::Pass,
+        }
+    }
+}
+
+#[pyclass(name = "Config")]
+#[derive(Clone)]
+pub struct PyConfig {
+    pub config: Config,
+}
+
+#[pymethods]
+impl PyConfig {
+    #[new]
+    fn new() -> Self {
+        PyConfig {
+            config: Config::default(),
+        }
+    }
+
+    fn set_decode_mode(&mut self, decode_mode: &PyDecodeMode) {
+        //! Set the decode mode used by the receive side of the driver.
+        //! Opting into `DecodeMode.decode()` lets `Driver.add_receiver`
+        //! callbacks see decoded i16 PCM.
+        self.config = self.config.clone().decode_mode(decode_mode.decode_mode);
+    }
+}
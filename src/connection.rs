@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use songbird::{Event, EventContext, EventHandler};
+use tokio::sync::Mutex;
+
+/// Live, mutable view of the driver's connection health.
+///
+/// Updated in place by [`ConnectionMonitor`] as Songbird's
+/// `DriverConnect`/`DriverReconnect`/`DriverDisconnect` events arrive, and
+/// read back through [`PyConnectionStats`].
+#[derive(Default)]
+pub struct ConnectionState {
+    pub connected: bool,
+    pub deafened: bool,
+    pub ssrc: Option<u32>,
+    pub channel_id: Option<u64>,
+    pub bitrate: Option<i32>,
+    pub packets_received: u64,
+    /// Whether a [`ConnectionMonitor`] has already been registered on the
+    /// driver, so repeated `connect` calls do not count events twice.
+    pub monitored: bool,
+    /// The last credentials a successful `connect` used, cached so
+    /// `reconnect`/`set_channel` can replay them with individual fields
+    /// mutated.
+    pub info: Option<crate::connection_info::PyConnectionInfo>,
+}
+
+/// A gauge-style snapshot of the driver's connection, returned from
+/// `Driver.get_connection_info`.
+///
+/// All fields are plain Python values so they can be pushed straight into a
+/// Prometheus/Redis collector without this crate depending on a metrics
+/// backend.
+///
+/// Note that Songbird does not surface the negotiated bitrate, round-trip
+/// time or crypto mode through the driver's public state: `bitrate` is the
+/// last value passed to `set_bitrate` (`None` while on `Max`/`Auto`), and RTT
+/// and crypto mode are therefore omitted here rather than reported inaccurately.
+#[pyclass(name = "ConnectionStats")]
+pub struct PyConnectionStats {
+    #[pyo3(get)]
+    pub connected: bool,
+    #[pyo3(get)]
+    pub ssrc: Option<u32>,
+    #[pyo3(get)]
+    pub channel_id: Option<u64>,
+    /// Last bitrate passed to `set_bitrate`; `None` while on `Max`/`Auto`.
+    /// This is the requested value, not a negotiated one.
+    #[pyo3(get)]
+    pub bitrate: Option<i32>,
+    #[pyo3(get)]
+    pub packets_received: u64,
+}
+
+/// Global event handler that keeps a [`ConnectionState`] in sync with the
+/// driver's connection lifecycle so a Python supervisor can spot a dropped
+/// RTP session and drive its own gateway re-handshake.
+#[derive(Clone)]
+pub struct ConnectionMonitor {
+    pub state: Arc<Mutex<ConnectionState>>,
+}
+
+#[songbird::async_trait]
+impl EventHandler for ConnectionMonitor {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        let mut state = self.state.lock().await;
+        match ctx {
+            EventContext::DriverConnect(data) | EventContext::DriverReconnect(data) => {
+                state.connected = true;
+                state.ssrc = Some(data.ssrc);
+                state.channel_id = data.channel_id.map(|c| c.0);
+            }
+            EventContext::DriverDisconnect(_) => {
+                state.connected = false;
+            }
+            EventContext::VoicePacket(_) => {
+                state.packets_received += 1;
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
@@ -0,0 +1,62 @@
+use pyo3::prelude::*;
+use songbird::id::{ChannelId, GuildId, UserId};
+use songbird::ConnectionInfo;
+
+/// A cacheable, mutable view of the gateway credentials a `Driver` connects
+/// with.
+///
+/// Because Songbird-Py drives the gateway manually, callers need to mutate
+/// individual fields (a fresh `session_id`/`token` after a
+/// `VOICE_SERVER_UPDATE`) and feed them back into `Driver.reconnect` rather
+/// than re-specifying all seven `connect` arguments.
+#[pyclass(name = "ConnectionInfo")]
+#[derive(Clone)]
+pub struct PyConnectionInfo {
+    #[pyo3(get, set)]
+    pub token: String,
+    #[pyo3(get, set)]
+    pub endpoint: String,
+    #[pyo3(get, set)]
+    pub session_id: String,
+    #[pyo3(get, set)]
+    pub guild_id: u64,
+    #[pyo3(get, set)]
+    pub channel_id: u64,
+    #[pyo3(get, set)]
+    pub user_id: u64,
+}
+
+#[pymethods]
+impl PyConnectionInfo {
+    #[new]
+    fn new(
+        token: String,
+        endpoint: String,
+        session_id: String,
+        guild_id: u64,
+        channel_id: u64,
+        user_id: u64,
+    ) -> Self {
+        PyConnectionInfo {
+            token,
+            endpoint: endpoint.replace("wss://", ""),
+            session_id,
+            guild_id,
+            channel_id,
+            user_id,
+        }
+    }
+}
+
+impl From<&PyConnectionInfo> for ConnectionInfo {
+    fn from(info: &PyConnectionInfo) -> Self {
+        ConnectionInfo {
+            channel_id: Some(ChannelId::from(info.channel_id)),
+            endpoint: info.endpoint.clone(),
+            guild_id: GuildId::from(info.guild_id),
+            session_id: info.session_id.clone(),
+            token: info.token.clone(),
+            user_id: UserId::from(info.user_id),
+        }
+    }
+}
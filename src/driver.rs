@@ -2,18 +2,24 @@ use std::sync::Arc;
 
 use pyo3::prelude::*;
 use songbird::driver::{Bitrate, Driver};
-use songbird::id::{ChannelId, GuildId, UserId};
 use songbird::Config;
 use tokio::sync::Mutex;
 
+use songbird::CoreEvent;
+
 use crate::config::PyConfig;
+use crate::connection::{ConnectionMonitor, ConnectionState, PyConnectionStats};
+use crate::connection_info::PyConnectionInfo;
+use crate::event::{PyEvent, PyEventHandler};
 use crate::exceptions::{CouldNotConnectToRTPError, UseAsyncConstructorError};
+use crate::receiver::{Receiver, RECEIVER_EVENTS};
 use crate::source::PySource;
 use crate::track_handle::PyTrackHandle;
 
 #[pyclass(name = "Driver")]
 pub struct PyDriver {
     driver: Arc<Mutex<Driver>>,
+    state: Arc<Mutex<ConnectionState>>,
 }
 
 #[pymethods]
@@ -48,6 +54,7 @@ impl PyDriver {
             // Make the config object
             Ok(PyDriver {
                 driver: Arc::new(Mutex::new(Driver::new(config))),
+                state: Arc::new(Mutex::new(ConnectionState::default())),
             })
         })
     }
@@ -73,27 +80,67 @@ impl PyDriver {
         //! * `channel_id` - Channel id you want to connect to.
         //! * `user_id` - User id of the current user.
         let driver = self.driver.clone();
-
-        let endpoint = endpoint.replace("wss://", "");
+        let state = self.state.clone();
+
+        let info = PyConnectionInfo {
+            token,
+            endpoint: endpoint.replace("wss://", ""),
+            session_id,
+            guild_id,
+            channel_id,
+            user_id,
+        };
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let res = driver
-                .lock()
-                .await
-                .connect(songbird::ConnectionInfo {
-                    channel_id: Some(ChannelId::from(channel_id)),
-                    endpoint: endpoint,
-                    guild_id: GuildId::from(guild_id),
-                    session_id: session_id,
-                    token: token,
-                    user_id: UserId::from(user_id),
-                })
-                .await;
-
-            match res {
-                Err(err) => Err(CouldNotConnectToRTPError::new_err(format!("{:?}", err))),
-                Ok(_) => Ok(()),
+            // Register the gauges once; they persist across later reconnects.
+            let monitor = ConnectionMonitor {
+                state: state.clone(),
+            };
+            if !std::mem::replace(&mut state.lock().await.monitored, true) {
+                let mut guard = driver.lock().await;
+                guard.add_global_event(CoreEvent::DriverConnect.into(), monitor.clone());
+                guard.add_global_event(CoreEvent::DriverReconnect.into(), monitor.clone());
+                guard.add_global_event(CoreEvent::DriverDisconnect.into(), monitor.clone());
+                guard.add_global_event(CoreEvent::VoicePacket.into(), monitor);
             }
+
+            connect_with(&driver, &state, info).await
+        })
+    }
+
+    fn reconnect<'p>(&'p self, py: Python<'p>, info: &PyConnectionInfo) -> PyResult<&'p PyAny> {
+        //! Reconnect using a (possibly mutated) `ConnectionInfo` cached from a
+        //! previous `connect`. Use this after a `VOICE_SERVER_UPDATE` region
+        //! migration updates the `session_id`/`token` instead of re-specifying
+        //! all seven `connect` arguments.
+        let driver = self.driver.clone();
+        let state = self.state.clone();
+        let info = info.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            connect_with(&driver, &state, info).await
+        })
+    }
+
+    fn set_channel<'p>(&'p self, py: Python<'p>, channel_id: u64) -> PyResult<&'p PyAny> {
+        //! Move the driver to another channel in the same guild by reconnecting
+        //! with the cached gateway credentials and the new channel id.
+        //! Songbird's `Driver` has no in-place channel switch, so this does
+        //! re-establish the RTP session. Requires an earlier `connect`.
+        let driver = self.driver.clone();
+        let state = self.state.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut info = match state.lock().await.info.clone() {
+                Some(info) => info,
+                None => {
+                    return Err(CouldNotConnectToRTPError::new_err(
+                        "`set_channel` requires a prior `connect`.",
+                    ))
+                }
+            };
+            info.channel_id = channel_id;
+            connect_with(&driver, &state, info).await
         })
     }
 
@@ -135,6 +182,61 @@ impl PyDriver {
         pyo3_asyncio::tokio::future_into_py(py, async move { Ok(driver.lock().await.is_mute()) })
     }
 
+    fn deafen<'p>(&'p self, py: Python<'p>, state: bool) -> PyResult<&'p PyAny> {
+        //! Sets the deafen state of the driver.
+        //! This is intent-only: it records a local flag and has no gateway
+        //! effect on its own. Because the caller drives the gateway manually
+        //! here, the caller must send its own voice-state update (with
+        //! `self_deaf`) for the deafen to take effect. Unlike `connect` it
+        //! never tears down the RTP session.
+        let conn_state = self.state.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            conn_state.lock().await.deafened = state;
+            Ok(())
+        })
+    }
+
+    fn is_deafened<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        //! Returns whether the driver is deafened.
+        let state = self.state.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move { Ok(state.lock().await.deafened) })
+    }
+
+    fn is_connected<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        //! Returns whether the UDP/WS voice connection is currently live.
+        //! Unlike `connect`, this stays accurate after a mid-session drop,
+        //! which is otherwise invisible to callers.
+        let state = self.state.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(state.lock().await.connected)
+        })
+    }
+
+    fn get_connection_info<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        //! Returns a `ConnectionStats` snapshot of the live connection:
+        //! whether it is up, the current SSRC and channel, the last bitrate
+        //! explicitly set (`None` while on `Max`/`Auto`; this is the requested
+        //! value, not a negotiated one) and the count of received voice
+        //! packets. Round-trip time and crypto mode are not exposed by
+        //! Songbird's driver state and so are omitted. Push these into your own
+        //! metrics collector to watch connection health across guilds.
+        let state = self.state.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let state = state.lock().await;
+            Ok(PyConnectionStats {
+                connected: state.connected,
+                ssrc: state.ssrc,
+                channel_id: state.channel_id,
+                bitrate: state.bitrate,
+                packets_received: state.packets_received,
+            })
+        })
+    }
+
     fn play_source<'p>(&'p self, py: Python<'p>, source: &'p PySource) -> PyResult<&'p PyAny> {
         //! Plays a Playable object.
         //! Playable are activated when you try to play them. That means all errors are
@@ -176,8 +278,10 @@ impl PyDriver {
     fn set_bitrate<'p>(&'p self, py: Python<'p>, bitrate: i32) -> PyResult<&'p PyAny> {
         //! Sets the bitrate to a i32
         let driver = self.driver.clone();
+        let state = self.state.clone();
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
+            state.lock().await.bitrate = Some(bitrate);
             Ok(driver
                 .lock()
                 .await
@@ -188,8 +292,10 @@ impl PyDriver {
     fn set_bitrate_to_max<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
         //! Sets the bitrate to a Bitrate::Max
         let driver = self.driver.clone();
+        let state = self.state.clone();
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
+            state.lock().await.bitrate = None;
             Ok(driver.lock().await.set_bitrate(Bitrate::Max))
         })
     }
@@ -197,8 +303,10 @@ impl PyDriver {
     fn set_bitrate_to_auto<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
         //! Sets the bitrate to Bitrate::Auto
         let driver = self.driver.clone();
+        let state = self.state.clone();
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
+            state.lock().await.bitrate = None;
             Ok(driver.lock().await.set_bitrate(Bitrate::Auto))
         })
     }
@@ -210,6 +318,54 @@ impl PyDriver {
         pyo3_asyncio::tokio::future_into_py(py, async move { Ok(driver.lock().await.stop()) })
     }
 
+    fn add_receiver<'p>(&'p self, py: Python<'p>, callback: PyObject) -> PyResult<&'p PyAny> {
+        //! Listen to the voices of other users in the channel.
+        //! `callback` is an async function called with a dict describing each
+        //! receive event (`speaking_state_update`, `speaking_update`,
+        //! `voice_packet`, `client_connect`, `client_disconnect`).
+        //!
+        //! When the driver's config uses `DecodeMode.decode()` the
+        //! `voice_packet` event carries decoded i16 PCM under `pcm` (which is
+        //! `None` when a packet decodes to no samples); otherwise it carries
+        //! the Opus payload under `payload`, which is still encrypted under
+        //! `DecodeMode.pass_through()`.
+        let driver = self.driver.clone();
+        let locals = pyo3_asyncio::tokio::get_current_locals(py)?;
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut driver = driver.lock().await;
+            let decode_mode = driver.config().decode_mode;
+            let receiver = Receiver::new(callback, locals, decode_mode);
+            for event in RECEIVER_EVENTS {
+                driver.add_global_event(event.into(), receiver.clone());
+            }
+            Ok(())
+        })
+    }
+
+    fn add_global_event<'p>(
+        &'p self,
+        py: Python<'p>,
+        event: &PyEvent,
+        callback: PyObject,
+    ) -> PyResult<&'p PyAny> {
+        //! Register an async `callback` to fire on a driver-wide `Event`.
+        //! Accepts track events, `Event.periodic`/`Event.delayed` timers and
+        //! the driver-connection events. The callback receives a dict
+        //! describing the event context.
+        let driver = self.driver.clone();
+        let event = event.event;
+        let locals = pyo3_asyncio::tokio::get_current_locals(py)?;
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            driver
+                .lock()
+                .await
+                .add_global_event(event, PyEventHandler::new(callback, locals));
+            Ok(())
+        })
+    }
+
     fn set_config<'p>(&'p self, py: Python<'p>, config: &PyConfig) -> PyResult<&'p PyAny> {
         //! Set the config for this Driver
         let driver = self.driver.clone();
@@ -220,3 +376,21 @@ impl PyDriver {
         })
     }
 }
+
+/// Connect the driver with `info`, caching it on the shared state so callers
+/// can mutate and replay it through `reconnect`/`set_channel`.
+async fn connect_with(
+    driver: &Arc<Mutex<Driver>>,
+    state: &Arc<Mutex<ConnectionState>>,
+    info: PyConnectionInfo,
+) -> PyResult<PyConnectionInfo> {
+    let res = driver.lock().await.connect((&info).into()).await;
+
+    match res {
+        Err(err) => Err(CouldNotConnectToRTPError::new_err(format!("{:?}", err))),
+        Ok(_) => {
+            state.lock().await.info = Some(info.clone());
+            Ok(info)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use songbird::{CoreEvent, Event, EventContext, EventHandler, TrackEvent};
+
+use pyo3_asyncio::TaskLocals;
+
+/// A Songbird `Event` that can be registered from Python.
+///
+/// Wraps the track, periodic, delayed and driver-connection events so they
+/// can be constructed with named builders instead of exposing Songbird's enum
+/// hierarchy directly.
+#[pyclass(name = "Event")]
+#[derive(Clone)]
+pub struct PyEvent {
+    pub event: Event,
+}
+
+#[pymethods]
+impl PyEvent {
+    #[staticmethod]
+    fn play() -> Self {
+        PyEvent {
+            event: Event::Track(TrackEvent::Play),
+        }
+    }
+
+    #[staticmethod]
+    fn pause() -> Self {
+        PyEvent {
+            event: Event::Track(TrackEvent::Pause),
+        }
+    }
+
+    #[staticmethod]
+    fn end() -> Self {
+        PyEvent {
+            event: Event::Track(TrackEvent::End),
+        }
+    }
+
+    #[staticmethod]
+    fn track_loop() -> Self {
+        PyEvent {
+            event: Event::Track(TrackEvent::Loop),
+        }
+    }
+
+    #[staticmethod]
+    #[args(phase = "None")]
+    fn periodic(duration: f64, phase: Option<f64>) -> Self {
+        //! Fire every `duration` seconds, optionally offset by `phase` seconds.
+        PyEvent {
+            event: Event::Periodic(
+                Duration::from_secs_f64(duration),
+                phase.map(Duration::from_secs_f64),
+            ),
+        }
+    }
+
+    #[staticmethod]
+    fn delayed(duration: f64) -> Self {
+        //! Fire once after `duration` seconds.
+        PyEvent {
+            event: Event::Delayed(Duration::from_secs_f64(duration)),
+        }
+    }
+
+    #[staticmethod]
+    fn driver_connect() -> Self {
+        PyEvent {
+            event: Event::Core(CoreEvent::DriverConnect),
+        }
+    }
+
+    #[staticmethod]
+    fn driver_disconnect() -> Self {
+        PyEvent {
+            event: Event::Core(CoreEvent::DriverDisconnect),
+        }
+    }
+
+    #[staticmethod]
+    fn driver_reconnect() -> Self {
+        PyEvent {
+            event: Event::Core(CoreEvent::DriverReconnect),
+        }
+    }
+}
+
+/// Adapts a Python async callback to Songbird's `EventHandler`.
+///
+/// The event loop is captured when the handler is registered; `act` builds a
+/// dict describing the event context and schedules the returned coroutine onto
+/// that loop without blocking Songbird's runtime.
+#[derive(Clone)]
+pub struct PyEventHandler {
+    callback: Py<PyAny>,
+    locals: TaskLocals,
+}
+
+impl PyEventHandler {
+    pub fn new(callback: Py<PyAny>, locals: TaskLocals) -> Self {
+        PyEventHandler { callback, locals }
+    }
+}
+
+#[songbird::async_trait]
+impl EventHandler for PyEventHandler {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        let locals = self.locals.clone();
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            match ctx {
+                EventContext::Track(states) => {
+                    dict.set_item("type", "track").ok();
+                    if let Some((state, _)) = states.first() {
+                        dict.set_item("position", state.position.as_secs_f64()).ok();
+                        dict.set_item("play_time", state.play_time.as_secs_f64())
+                            .ok();
+                    }
+                }
+                EventContext::DriverConnect(data) => {
+                    dict.set_item("type", "driver_connect").ok();
+                    dict.set_item("ssrc", data.ssrc).ok();
+                    dict.set_item("channel_id", data.channel_id.map(|c| c.0)).ok();
+                }
+                EventContext::DriverReconnect(data) => {
+                    dict.set_item("type", "driver_reconnect").ok();
+                    dict.set_item("ssrc", data.ssrc).ok();
+                    dict.set_item("channel_id", data.channel_id.map(|c| c.0)).ok();
+                }
+                EventContext::DriverDisconnect(data) => {
+                    dict.set_item("type", "driver_disconnect").ok();
+                    dict.set_item("reason", format!("{:?}", data.reason)).ok();
+                }
+                _ => {
+                    dict.set_item("type", "other").ok();
+                }
+            }
+
+            if let Ok(coro) = self.callback.call1(py, (dict,)) {
+                if let Ok(fut) = pyo3_asyncio::into_future_with_locals(&locals, coro.as_ref(py)) {
+                    pyo3_asyncio::tokio::get_runtime().spawn(async move {
+                        let _ = fut.await;
+                    });
+                }
+            }
+        });
+
+        None
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use tokio::runtime::Runtime;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::prelude::*;
+
+/// Shared buffer of formatted log lines filled by [`PyLogLayer`] and drained
+/// by [`LoggingHandle`].
+type LogQueue = Arc<Mutex<VecDeque<PyLogRecord>>>;
+
+/// A single formatted `tracing` record handed to Python.
+#[pyclass(name = "LogRecord")]
+#[derive(Clone)]
+pub struct PyLogRecord {
+    #[pyo3(get)]
+    pub level: String,
+    #[pyo3(get)]
+    pub target: String,
+    #[pyo3(get)]
+    pub message: String,
+}
+
+/// `tracing` layer that formats each event and pushes it onto the shared
+/// queue. Songbird and its dependencies emit through `tracing`, so installing
+/// this makes their diagnostics visible to Python.
+struct PyLogLayer {
+    queue: LogQueue,
+    min_level: Level,
+}
+
+/// Collects the `message` field out of a `tracing` event.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for PyLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > self.min_level {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.push_back(PyLogRecord {
+                level: metadata.level().to_string(),
+                target: metadata.target().to_string(),
+                message: visitor.message,
+            });
+        }
+    }
+}
+
+/// Handle returned by [`init_logging`].
+///
+/// Owns the Tokio runtime that drives `future_into_py` (it is installed as
+/// pyo3-asyncio's runtime, so logging and async execution share one
+/// lifecycle) together with the shared log queue. `start`/`stop` control a
+/// background drain task that forwards records to the callback; `get_logs`
+/// lets Python pull them on demand instead.
+#[pyclass(name = "LoggingHandle")]
+pub struct LoggingHandle {
+    queue: LogQueue,
+    callback: Py<PyAny>,
+    runtime: &'static Runtime,
+    running: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl LoggingHandle {
+    fn start(&self) -> PyResult<()> {
+        //! Start the background runtime task that drains queued records to the
+        //! callback. A second call while already running is a no-op.
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let queue = self.queue.clone();
+        let callback = self.callback.clone();
+        let running = self.running.clone();
+        self.runtime.spawn(async move {
+            while running.load(Ordering::SeqCst) {
+                let drained: Vec<PyLogRecord> = {
+                    let mut queue = queue.lock().unwrap();
+                    queue.drain(..).collect()
+                };
+                Python::with_gil(|py| {
+                    for record in &drained {
+                        let _ = callback.call1(py, (record.clone(),));
+                    }
+                });
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        });
+
+        Ok(())
+    }
+
+    fn stop(&self) {
+        //! Stop the background drain task. Queued records remain and can still
+        //! be pulled with `get_logs`.
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    fn get_logs<'p>(&'p self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        //! Drain the queued log records and hand each to the callback,
+        //! returning the list that was dispatched. Use this to forward
+        //! Songbird's internals into `logging.getLogger("songbird")` without
+        //! running the background task.
+        let queue = self.queue.clone();
+        let callback = self.callback.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let drained: Vec<PyLogRecord> = {
+                let mut queue = queue.lock().unwrap();
+                queue.drain(..).collect()
+            };
+
+            Python::with_gil(|py| {
+                for record in &drained {
+                    let _ = callback.call1(py, (record.clone(),));
+                }
+            });
+
+            Ok(drained)
+        })
+    }
+}
+
+/// Install a `tracing_subscriber` layer that forwards Songbird's log records
+/// to `callback`, and take ownership of the Tokio runtime used by the rest of
+/// the crate so logging and async execution share one lifecycle.
+///
+/// Pass `debug=True` to capture `DEBUG`/`TRACE` level records as well.
+#[pyfunction]
+#[args(debug = "false")]
+pub fn init_logging(callback: Py<PyAny>, debug: bool) -> PyResult<LoggingHandle> {
+    let queue: LogQueue = Arc::new(Mutex::new(VecDeque::new()));
+    let min_level = if debug { Level::TRACE } else { Level::INFO };
+
+    let layer = PyLogLayer {
+        queue: queue.clone(),
+        min_level,
+    };
+    // `try_init` rather than `init` so a second call (or a pre-existing global
+    // subscriber) surfaces as a Python error instead of panicking.
+    tracing_subscriber::registry()
+        .with(layer)
+        .try_init()
+        .map_err(|err| PyRuntimeError::new_err(format!("{}", err)))?;
+
+    // Own the runtime and install it as the one pyo3-asyncio's `future_into_py`
+    // drives, so logging and async execution share a single lifecycle. It is
+    // leaked to `'static` because the process keeps a single runtime alive.
+    let runtime: &'static Runtime = Box::leak(Box::new(
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| PyRuntimeError::new_err(format!("{}", err)))?,
+    ));
+    pyo3_asyncio::tokio::init_with_runtime(runtime)
+        .map_err(|_| PyRuntimeError::new_err("the async runtime is already initialised"))?;
+
+    Ok(LoggingHandle {
+        queue,
+        callback,
+        runtime,
+        running: Arc::new(AtomicBool::new(false)),
+    })
+}
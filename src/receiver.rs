@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use songbird::driver::DecodeMode;
+use songbird::model::payload::{ClientConnect, ClientDisconnect, Speaking};
+use songbird::{CoreEvent, Event, EventContext, EventHandler};
+use tokio::sync::Mutex;
+
+use pyo3_asyncio::TaskLocals;
+
+/// Event handler wired onto a `Driver` that forwards the receive-side
+/// `CoreEvent`s to a single Python callback.
+///
+/// Songbird calls `act` on its own runtime and expects it to return quickly,
+/// so the handler never blocks on Python: it looks the sender up in the shared
+/// SSRC map, builds a plain dict describing the event and dispatches the user's
+/// coroutine onto the event loop captured when the receiver was registered.
+#[derive(Clone)]
+pub struct Receiver {
+    callback: Py<PyAny>,
+    locals: TaskLocals,
+    decode_mode: DecodeMode,
+    /// Maps each sender's SSRC to their resolved `UserId`, populated as
+    /// `SpeakingStateUpdate` events arrive.
+    ssrc_map: Arc<Mutex<HashMap<u32, u64>>>,
+}
+
+impl Receiver {
+    pub fn new(callback: Py<PyAny>, locals: TaskLocals, decode_mode: DecodeMode) -> Self {
+        Receiver {
+            callback,
+            locals,
+            decode_mode,
+            ssrc_map: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Schedule the Python coroutine returned by the callback onto the loop
+    /// captured at registration time.
+    fn dispatch(&self, kind: &str, build: impl FnOnce(Python, &PyDict) -> PyResult<()>) {
+        let locals = self.locals.clone();
+        Python::with_gil(|py| {
+            let ctx = PyDict::new(py);
+            ctx.set_item("type", kind).ok();
+            if build(py, ctx).is_err() {
+                return;
+            }
+            if let Ok(coro) = self.callback.call1(py, (ctx,)) {
+                if let Ok(fut) =
+                    pyo3_asyncio::into_future_with_locals(&locals, coro.as_ref(py))
+                {
+                    pyo3_asyncio::tokio::get_runtime().spawn(async move {
+                        let _ = fut.await;
+                    });
+                }
+            }
+        });
+    }
+}
+
+#[songbird::async_trait]
+impl EventHandler for Receiver {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        match ctx {
+            EventContext::SpeakingStateUpdate(Speaking {
+                ssrc, user_id, ..
+            }) => {
+                if let Some(user_id) = user_id {
+                    self.ssrc_map.lock().await.insert(*ssrc, user_id.0);
+                }
+                self.dispatch("speaking_state_update", |_py, dict| {
+                    dict.set_item("ssrc", ssrc)?;
+                    dict.set_item("user_id", user_id.map(|u| u.0))?;
+                    Ok(())
+                });
+            }
+            EventContext::SpeakingUpdate(data) => {
+                self.dispatch("speaking_update", |_py, dict| {
+                    dict.set_item("ssrc", data.ssrc)?;
+                    dict.set_item("speaking", data.speaking)?;
+                    Ok(())
+                });
+            }
+            EventContext::VoicePacket(data) => {
+                let ssrc = data.packet.ssrc;
+                let user_id = self.ssrc_map.lock().await.get(&ssrc).copied();
+                let sequence = data.packet.sequence.0;
+                let timestamp = data.packet.timestamp.0;
+                // Strip the RTP header extension and the crypto auth tag so
+                // consumers see actual Opus frames (still encrypted under
+                // `DecodeMode::Pass`), not the whole payload.
+                // Bounds are taken from the packet and may not hold on a
+                // malformed/short packet, so slice with checked arithmetic
+                // rather than panicking inside Songbird's event loop.
+                let full = &data.packet.payload;
+                let opus = full
+                    .len()
+                    .checked_sub(data.payload_end_pad)
+                    .and_then(|end| full.get(data.payload_offset..end))
+                    .unwrap_or(&[])
+                    .to_vec();
+                // `audio` is only populated under `DecodeMode::Decode`, and
+                // even then is `None` when decoding yields no samples; callers
+                // see `pcm = None` in that case.
+                let pcm = data.audio.clone();
+                self.dispatch("voice_packet", move |_py, dict| {
+                    dict.set_item("ssrc", ssrc)?;
+                    dict.set_item("user_id", user_id)?;
+                    dict.set_item("sequence", sequence)?;
+                    dict.set_item("timestamp", timestamp)?;
+                    match self.decode_mode {
+                        DecodeMode::Decode => {
+                            dict.set_item("pcm", pcm)?;
+                        }
+                        _ => {
+                            dict.set_item("payload", opus)?;
+                        }
+                    }
+                    Ok(())
+                });
+            }
+            EventContext::ClientConnect(ClientConnect {
+                audio_ssrc,
+                user_id,
+                ..
+            }) => {
+                self.ssrc_map.lock().await.insert(*audio_ssrc, user_id.0);
+                self.dispatch("client_connect", |_py, dict| {
+                    dict.set_item("ssrc", audio_ssrc)?;
+                    dict.set_item("user_id", user_id.0)?;
+                    Ok(())
+                });
+            }
+            EventContext::ClientDisconnect(ClientDisconnect { user_id }) => {
+                self.ssrc_map
+                    .lock()
+                    .await
+                    .retain(|_, uid| *uid != user_id.0);
+                self.dispatch("client_disconnect", |_py, dict| {
+                    dict.set_item("user_id", user_id.0)?;
+                    Ok(())
+                });
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+/// The set of `CoreEvent`s a `Receiver` needs to cover the receive surface.
+pub const RECEIVER_EVENTS: [CoreEvent; 5] = [
+    CoreEvent::SpeakingStateUpdate,
+    CoreEvent::SpeakingUpdate,
+    CoreEvent::VoicePacket,
+    CoreEvent::ClientConnect,
+    CoreEvent::ClientDisconnect,
+];
@@ -0,0 +1,50 @@
+use pyo3::prelude::*;
+use songbird::tracks::TrackHandle;
+
+use crate::event::{PyEvent, PyEventHandler};
+use crate::exceptions::CouldNotConnectToRTPError;
+
+#[pyclass(name = "TrackHandle")]
+pub struct PyTrackHandle {
+    track_handle: TrackHandle,
+}
+
+impl From<TrackHandle> for PyTrackHandle {
+    fn from(track_handle: TrackHandle) -> Self {
+        PyTrackHandle { track_handle }
+    }
+}
+
+#[pymethods]
+impl PyTrackHandle {
+    fn play(&self) -> PyResult<()> {
+        //! Resumes playback of the track.
+        self.track_handle
+            .play()
+            .map_err(|err| CouldNotConnectToRTPError::new_err(format!("{:?}", err)))
+    }
+
+    fn pause(&self) -> PyResult<()> {
+        //! Pauses playback of the track.
+        self.track_handle
+            .pause()
+            .map_err(|err| CouldNotConnectToRTPError::new_err(format!("{:?}", err)))
+    }
+
+    fn stop(&self) -> PyResult<()> {
+        //! Stops the track. A stopped track can not be restarted.
+        self.track_handle
+            .stop()
+            .map_err(|err| CouldNotConnectToRTPError::new_err(format!("{:?}", err)))
+    }
+
+    fn add_event(&self, event: &PyEvent, callback: PyObject, py: Python) -> PyResult<()> {
+        //! Register an async `callback` to fire on this track's `Event`.
+        //! Accepts track events and `Event.periodic`/`Event.delayed` timers.
+        //! The callback receives a dict describing the event context.
+        let locals = pyo3_asyncio::tokio::get_current_locals(py)?;
+        self.track_handle
+            .add_event(event.event, PyEventHandler::new(callback, locals))
+            .map_err(|err| CouldNotConnectToRTPError::new_err(format!("{:?}", err)))
+    }
+}